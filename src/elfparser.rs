@@ -1,9 +1,60 @@
+use std::error;
+use std::fmt;
+use std::io;
 use std::io::BufReader;
+use std::io::Cursor;
 use std::io::Read;
-use std::io::Result;
+use std::io::Seek;
+use std::io::SeekFrom;
 use std::fs::File;
-use std::io::Error;
-use std::io::ErrorKind;
+
+pub type Result<T> = ::std::result::Result<T, ElfParseError>;
+
+/// Everything that can go wrong while parsing an ELF file, instead of
+/// panicking on malformed input or collapsing every failure into an opaque
+/// `io::Error`. Modeled on the error types of the `elfload` and `object`
+/// crates.
+#[derive(Debug)]
+pub enum ElfParseError {
+    WrongMagic([u8; 4]),
+    OutOfBytes,
+    UnknownClass(u8),
+    UnknownEndianness(u8),
+    UnknownVersion(u32),
+    UnknownMachine(u16),
+    InvalidFileHeader(&'static str),
+    Io(io::Error)
+}
+
+impl fmt::Display for ElfParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ElfParseError::WrongMagic(bytes) => write!(f, "wrong ELF magic: {:?}", bytes),
+            ElfParseError::OutOfBytes => write!(f, "ran out of bytes while parsing"),
+            ElfParseError::UnknownClass(b) => write!(f, "unknown ELF class: {}", b),
+            ElfParseError::UnknownEndianness(b) => write!(f, "unknown ELF endianness: {}", b),
+            ElfParseError::UnknownVersion(v) => write!(f, "unknown ELF version: {}", v),
+            ElfParseError::UnknownMachine(m) => write!(f, "unknown ELF machine: {}", m),
+            ElfParseError::InvalidFileHeader(reason) => write!(f, "invalid ELF file header: {}", reason),
+            ElfParseError::Io(ref e) => write!(f, "I/O error: {}", e)
+        }
+    }
+}
+
+impl error::Error for ElfParseError {
+    fn description(&self) -> &str {
+        "failed to parse ELF file"
+    }
+}
+
+impl From<io::Error> for ElfParseError {
+    fn from(e: io::Error) -> ElfParseError {
+        match e.kind() {
+            io::ErrorKind::UnexpectedEof => ElfParseError::OutOfBytes,
+            _ => ElfParseError::Io(e)
+        }
+    }
+}
 
 #[derive(Debug, PartialEq,Copy,Clone)]
 pub enum ElfClass {
@@ -46,9 +97,12 @@ pub enum ElfMachine {
     M88K,
     I860,
     MIPS,
+    AVR,
     Processor(u16)
 }
 
+pub const EM_AVR: u16 = 83;
+
 
 pub struct ElfHeader {
     pub class: ElfClass,
@@ -69,12 +123,148 @@ pub struct ElfHeader {
     pub e_shstrndx: u16
 }
 
+pub const PT_LOAD: u32 = 1;
+
+pub const PF_X: u32 = 1;
+pub const PF_W: u32 = 2;
+pub const PF_R: u32 = 4;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
 pub struct ProgramHeader {
+    pub p_type: u32,
+    pub p_offset: u32,
+    pub p_vaddr: u32,
+    pub p_paddr: u32,
+    pub p_filesz: u32,
+    pub p_memsz: u32,
+    pub p_flags: u32,
+    pub p_align: u32
+}
+
+impl ProgramHeader {
+    fn is_load(&self) -> bool {
+        self.p_type == PT_LOAD
+    }
+}
+
+/// A PT_LOAD program header, boiled down to what a loader actually needs:
+/// where the bytes go, how many of them there are, and the permissions the
+/// segment should end up with once it is mapped.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct LoadSegment {
+    pub offset: u32,
+    pub vaddr: u32,
+    pub paddr: u32,
+    pub filesz: u32,
+    pub memsz: u32,
+    pub zero_pad: u32,
+    pub r: bool,
+    pub w: bool,
+    pub x: bool
+}
+
+fn to_load_segment(ph: &ProgramHeader) -> LoadSegment {
+    LoadSegment {
+        offset: ph.p_offset,
+        vaddr: ph.p_vaddr,
+        paddr: ph.p_paddr,
+        filesz: ph.p_filesz,
+        memsz: ph.p_memsz,
+        // A malformed header can claim more file bytes than memory bytes;
+        // clamp to zero rather than underflow on bytes we don't trust.
+        zero_pad: ph.p_memsz.saturating_sub(ph.p_filesz),
+        r: ph.p_flags & PF_R != 0,
+        w: ph.p_flags & PF_W != 0,
+        x: ph.p_flags & PF_X != 0
+    }
+}
+
+/// Returns the PT_LOAD segments of `program_headers`, ready to be copied
+/// into a memory image.
+pub fn load_segments(program_headers: &[ProgramHeader]) -> Vec<LoadSegment> {
+    program_headers.iter().filter(|ph| ph.is_load()).map(to_load_segment).collect()
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SectionHeader {
+    pub sh_name: u32,
+    pub sh_type: u32,
+    pub sh_flags: u32,
+    pub sh_addr: u32,
+    pub sh_offset: u32,
+    pub sh_size: u32,
+    pub sh_link: u32,
+    pub sh_info: u32,
+    pub sh_addralign: u32,
+    pub sh_entsize: u32
+}
+
+/// A section header with its name already resolved out of the section-name
+/// string table, so callers never have to deal with `sh_name` offsets
+/// themselves.
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub header: SectionHeader,
+    pub name: String
+}
+
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_DYNSYM: u32 = 11;
+
+pub const STT_FUNC: u8 = 2;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SymbolTableEntry {
+    pub st_name: u32,
+    pub st_value: u32,
+    pub st_size: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16
+}
+
+impl SymbolTableEntry {
+    fn symbol_type(&self) -> u8 {
+        self.st_info & 0xf
+    }
+
+    fn is_function(&self) -> bool {
+        self.symbol_type() == STT_FUNC
+    }
+}
+
+/// A symbol table entry with its name already resolved out of the linked
+/// string table.
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub header: SymbolTableEntry,
+    pub name: String
 }
 
 pub struct ElfFile {
-    Header: ElfHeader,
-    ProgramHeader: ProgramHeader
+    pub header: ElfHeader,
+    pub program_headers: Vec<ProgramHeader>,
+    pub sections: Vec<Section>,
+    pub symbols: Vec<Symbol>
+}
+
+impl ElfFile {
+    /// Looks up a section by its name (e.g. `.text`, `.data`, `.bss`).
+    pub fn section_by_name(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|s| s.name == name)
+    }
+
+    /// Looks up a symbol's address by name, e.g. to resolve `main` or an
+    /// interrupt vector when setting a breakpoint.
+    pub fn symbol_by_name(&self, name: &str) -> Option<u32> {
+        self.symbols.iter().find(|s| s.name == name).map(|s| s.header.st_value)
+    }
+
+    /// The subset of `symbols` that name a function, for disassembling by
+    /// function or listing callable entry points.
+    pub fn function_symbols(&self) -> Vec<&Symbol> {
+        self.symbols.iter().filter(|s| s.header.is_function()).collect()
+    }
 }
 
 
@@ -83,7 +273,7 @@ fn get_elf_class (byte : u8) -> Result<ElfClass> {
         0u8 => Ok(ElfClass::NoClass),
         1u8 => Ok(ElfClass::Bit32),
         2u8 => Ok(ElfClass::Bit64),
-        _ => Err(Error::new(ErrorKind::Other, "Unrecognized ElfClass"))
+        _ => Err(ElfParseError::UnknownClass(byte))
     }
 }
 
@@ -92,7 +282,7 @@ fn get_elf_endianness (byte : u8) -> Result<ElfEndianness> {
         0u8 => Ok(ElfEndianness::Unknown),
         1u8 => Ok(ElfEndianness::Little),
         2u8 => Ok(ElfEndianness::Big),
-        _ => Err(Error::new(ErrorKind::Other, "Unrecognized ElfEndianness"))
+        _ => Err(ElfParseError::UnknownEndianness(byte))
     }
 }
 
@@ -100,7 +290,7 @@ fn get_elf_ident_version (byte : u8) -> Result<ElfVersion> {
     match byte {
         0u8 => Ok(ElfVersion::Invalid),
         1u8 => Ok(ElfVersion::Current),
-        _ => Err(Error::new(ErrorKind::Other, "Unrecognized ElfVersion"))
+        _ => Err(ElfParseError::UnknownVersion(byte as u32))
     }
 }
 
@@ -112,7 +302,7 @@ fn get_elf_filetype (data: u16) -> Result<ElfFileType> {
         3 => Ok(ElfFileType::SharedObject),
         4 => Ok(ElfFileType::Core),
         x @ 0xff00 ... 0xffff => Ok(ElfFileType::ProcessorSpecific(x)),
-        _ => Err(Error::new(ErrorKind::Other, "Unrecognized ElfFileType"))
+        _ => Err(ElfParseError::InvalidFileHeader("unrecognized e_type"))
     }
 }
 
@@ -126,6 +316,7 @@ fn get_elf_machine (data: u16) -> Result<ElfMachine> {
         5 => Ok(ElfMachine::M88K),
         6 => Ok(ElfMachine::I860),
         7 => Ok(ElfMachine::MIPS),
+        EM_AVR => Ok(ElfMachine::AVR),
         x @ _ => Ok(ElfMachine::Processor(x))
     }
 }
@@ -134,7 +325,7 @@ fn get_elf_version (data: u32) -> Result<ElfVersion> {
     match data {
         0 => Ok(ElfVersion::Invalid),
         1 => Ok(ElfVersion::Current),
-        _ => Err(Error::new(ErrorKind::Other, "Unrecognized ElfVersion"))
+        _ => Err(ElfParseError::UnknownVersion(data))
     }
 }
 
@@ -161,7 +352,7 @@ impl <'a> EndianAwareReader for ElfReader<'a> {
         try! (self.inner.read_exact(&mut buf));
 
         match self.endianness {
-            ElfEndianness::Unknown => Err(Error::new(ErrorKind::Other, "Cannot proceed with unknown ElfEndianness")),
+            ElfEndianness::Unknown => Err(ElfParseError::InvalidFileHeader("cannot read a value with unknown endianness")),
             ElfEndianness::Little => Ok((buf[1] as u16) << 8 | (buf[0] as u16)),
             ElfEndianness::Big => Ok((buf[0] as u16) << 8 | (buf[1] as u16))
         }
@@ -172,7 +363,7 @@ impl <'a> EndianAwareReader for ElfReader<'a> {
         try! (self.inner.read_exact(&mut buf));
 
         match self.endianness {
-            ElfEndianness::Unknown => Err(Error::new(ErrorKind::Other, "Cannot proceed with unknown ElfEndianness")),
+            ElfEndianness::Unknown => Err(ElfParseError::InvalidFileHeader("cannot read a value with unknown endianness")),
             ElfEndianness::Little => Ok((buf[3] as u32) << 24 | (buf[2] as u32) << 16 | (buf[1] as u32) << 8 | buf[0] as u32),
             ElfEndianness::Big => Ok((buf[0] as u32) << 24 | (buf[1] as u32) << 16 | (buf[2] as u32) << 8 | buf[3] as u32)
         }
@@ -180,9 +371,12 @@ impl <'a> EndianAwareReader for ElfReader<'a> {
 }
 
 pub fn read_elf_header (filename: &str) -> Result<ElfHeader> {
-    let f = File::open(filename);
-    let mut reader = BufReader::new(f.unwrap());
+    let f = try!(File::open(filename));
+    let mut reader = BufReader::new(f);
+    read_header(&mut reader)
+}
 
+fn read_header<R: Read>(reader: &mut R) -> Result<ElfHeader> {
     let mut h = ElfHeader{
         class: ElfClass::NoClass,
         endianness: ElfEndianness::Unknown,
@@ -204,24 +398,253 @@ pub fn read_elf_header (filename: &str) -> Result<ElfHeader> {
     };
 
     let mut id = [0; 16];
-    try!(reader.read(&mut id));
-    assert_eq! (id[0], 0x7F);
-    assert_eq! (id[1], 'E' as u8);
-    assert_eq! (id[2], 'L' as u8);
-    assert_eq! (id[3], 'F' as u8);
+    try!(reader.read_exact(&mut id));
+    if id[0..4] != [0x7F, 'E' as u8, 'L' as u8, 'F' as u8] {
+        return Err(ElfParseError::WrongMagic([id[0], id[1], id[2], id[3]]));
+    }
 
     h.class = try!(get_elf_class(id[4] as u8));
     h.endianness = try!(get_elf_endianness(id[5] as u8));
     h.ident_version = try!(get_elf_ident_version(id[6] as u8));
 
     {
-        let mut elfreader = ElfReader { inner : &mut reader, endianness : h.endianness};
+        let mut elfreader = ElfReader { inner : reader, endianness : h.endianness};
         h.filetype = try!(elfreader.read_u16().and_then(get_elf_filetype));
         h.machine = try!(elfreader.read_u16().and_then(get_elf_machine));
         h.version = try!(elfreader.read_u32().and_then(get_elf_version));
         h.entry = try!(elfreader.read_u32());
         h.phoff = try!(elfreader.read_u32());
+        h.e_shoff = try!(elfreader.read_u32());
+        h.e_flags = try!(elfreader.read_u32());
+        h.e_ehsize = try!(elfreader.read_u16());
+        h.e_phentsize = try!(elfreader.read_u16());
+        h.e_phnum = try!(elfreader.read_u16());
+        h.e_shentsize = try!(elfreader.read_u16());
+        h.e_shnum = try!(elfreader.read_u16());
+        h.e_shstrndx = try!(elfreader.read_u16());
     }
     Ok(h)
 }
 
+fn read_program_header<R: Read>(reader: &mut R, endianness: ElfEndianness) -> Result<ProgramHeader> {
+    let mut elfreader = ElfReader { inner: reader, endianness: endianness };
+    Ok(ProgramHeader {
+        p_type: try!(elfreader.read_u32()),
+        p_offset: try!(elfreader.read_u32()),
+        p_vaddr: try!(elfreader.read_u32()),
+        p_paddr: try!(elfreader.read_u32()),
+        p_filesz: try!(elfreader.read_u32()),
+        p_memsz: try!(elfreader.read_u32()),
+        p_flags: try!(elfreader.read_u32()),
+        p_align: try!(elfreader.read_u32())
+    })
+}
+
+fn read_program_headers<R: Read + Seek>(reader: &mut R, header: &ElfHeader) -> Result<Vec<ProgramHeader>> {
+    try!(reader.seek(SeekFrom::Start(header.phoff as u64)));
+
+    let mut program_headers = Vec::with_capacity(header.e_phnum as usize);
+    for _ in 0..header.e_phnum {
+        program_headers.push(try!(read_program_header(reader, header.endianness)));
+    }
+    Ok(program_headers)
+}
+
+fn read_section_header<R: Read>(reader: &mut R, endianness: ElfEndianness) -> Result<SectionHeader> {
+    let mut elfreader = ElfReader { inner: reader, endianness: endianness };
+    Ok(SectionHeader {
+        sh_name: try!(elfreader.read_u32()),
+        sh_type: try!(elfreader.read_u32()),
+        sh_flags: try!(elfreader.read_u32()),
+        sh_addr: try!(elfreader.read_u32()),
+        sh_offset: try!(elfreader.read_u32()),
+        sh_size: try!(elfreader.read_u32()),
+        sh_link: try!(elfreader.read_u32()),
+        sh_info: try!(elfreader.read_u32()),
+        sh_addralign: try!(elfreader.read_u32()),
+        sh_entsize: try!(elfreader.read_u32())
+    })
+}
+
+fn read_section_headers<R: Read + Seek>(reader: &mut R, header: &ElfHeader) -> Result<Vec<SectionHeader>> {
+    try!(reader.seek(SeekFrom::Start(header.e_shoff as u64)));
+
+    let mut section_headers = Vec::with_capacity(header.e_shnum as usize);
+    for _ in 0..header.e_shnum {
+        section_headers.push(try!(read_section_header(reader, header.endianness)));
+    }
+    Ok(section_headers)
+}
+
+fn read_string_table<R: Read + Seek>(reader: &mut R, sh: &SectionHeader) -> Result<Vec<u8>> {
+    try!(reader.seek(SeekFrom::Start(sh.sh_offset as u64)));
+
+    let mut strtab = vec![0; sh.sh_size as usize];
+    try!(reader.read_exact(&mut strtab));
+    Ok(strtab)
+}
+
+fn name_at(strtab: &[u8], offset: u32) -> Result<String> {
+    let start = offset as usize;
+    let bytes = match strtab.get(start..) {
+        Some(bytes) => bytes,
+        None => return Err(ElfParseError::InvalidFileHeader("name offset past the end of the string table"))
+    };
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+
+    match ::std::str::from_utf8(&bytes[..end]) {
+        Ok(name) => Ok(name.to_string()),
+        Err(_) => Err(ElfParseError::InvalidFileHeader("name in string table is not valid UTF-8"))
+    }
+}
+
+fn read_sections<R: Read + Seek>(reader: &mut R, header: &ElfHeader) -> Result<Vec<Section>> {
+    let section_headers = try!(read_section_headers(reader, header));
+    if section_headers.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let strtab_header = match section_headers.get(header.e_shstrndx as usize) {
+        Some(sh) => sh,
+        None => return Err(ElfParseError::InvalidFileHeader("e_shstrndx out of range"))
+    };
+    let strtab = try!(read_string_table(reader, strtab_header));
+
+    let mut sections = Vec::with_capacity(section_headers.len());
+    for sh in section_headers {
+        sections.push(Section { name: try!(name_at(&strtab, sh.sh_name)), header: sh });
+    }
+    Ok(sections)
+}
+
+fn read_symbol_table_entry<R: Read>(reader: &mut R, endianness: ElfEndianness) -> Result<SymbolTableEntry> {
+    let mut elfreader = ElfReader { inner: reader, endianness: endianness };
+    Ok(SymbolTableEntry {
+        st_name: try!(elfreader.read_u32()),
+        st_value: try!(elfreader.read_u32()),
+        st_size: try!(elfreader.read_u32()),
+        st_info: try!(elfreader.read_u8()),
+        st_other: try!(elfreader.read_u8()),
+        st_shndx: try!(elfreader.read_u16())
+    })
+}
+
+fn read_symbol_table<R: Read + Seek>(reader: &mut R, header: &ElfHeader, symtab: &SectionHeader, sections: &[Section]) -> Result<Vec<Symbol>> {
+    let strtab_section = match sections.get(symtab.sh_link as usize) {
+        Some(s) => s,
+        None => return Err(ElfParseError::InvalidFileHeader("sh_link out of range for a symbol table"))
+    };
+    let strtab = try!(read_string_table(reader, &strtab_section.header));
+
+    let entsize = if symtab.sh_entsize == 0 { 16 } else { symtab.sh_entsize };
+    let count = symtab.sh_size / entsize;
+
+    try!(reader.seek(SeekFrom::Start(symtab.sh_offset as u64)));
+    let mut symbols = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let entry = try!(read_symbol_table_entry(reader, header.endianness));
+        symbols.push(Symbol { name: try!(name_at(&strtab, entry.st_name)), header: entry });
+    }
+    Ok(symbols)
+}
+
+/// Reads every `SHT_SYMTAB`/`SHT_DYNSYM` section's symbols, resolving each
+/// one's name against the string table its section header links to.
+fn read_symbols<R: Read + Seek>(reader: &mut R, header: &ElfHeader, sections: &[Section]) -> Result<Vec<Symbol>> {
+    let mut symbols = Vec::new();
+    for sh in sections {
+        if sh.header.sh_type == SHT_SYMTAB || sh.header.sh_type == SHT_DYNSYM {
+            symbols.extend(try!(read_symbol_table(reader, header, &sh.header, sections)));
+        }
+    }
+    Ok(symbols)
+}
+
+/// Parses an already-loaded ELF image. Unlike `read_elf`, this never touches
+/// the filesystem, so callers can hand it bytes from `include_bytes!`, an
+/// mmap, or anywhere else an AVR image might come from.
+pub fn parse(bytes: &[u8]) -> Result<ElfFile> {
+    let mut cursor = Cursor::new(bytes);
+    let header = try!(read_header(&mut cursor));
+    let program_headers = try!(read_program_headers(&mut cursor, &header));
+    let sections = try!(read_sections(&mut cursor, &header));
+    let symbols = try!(read_symbols(&mut cursor, &header, &sections));
+
+    Ok(ElfFile { header: header, program_headers: program_headers, sections: sections, symbols: symbols })
+}
+
+/// Reads `filename` into memory and parses it with `parse`: the ELF header,
+/// program header table, sections and symbols. The PT_LOAD segments can then
+/// be found via `load_segments`.
+pub fn read_elf(filename: &str) -> Result<ElfFile> {
+    let mut f = try!(File::open(filename));
+    let mut bytes = Vec::new();
+    try!(f.read_to_end(&mut bytes));
+    parse(&bytes)
+}
+
+/// The address at which avr-gcc's linker scripts place SRAM: a LOAD
+/// segment's `p_vaddr` is offset by this much when the segment actually
+/// belongs in data memory rather than flash.
+pub const AVR_SRAM_BASE: u32 = 0x800000;
+
+/// A ready-to-execute AVR memory image produced by `load_image`: flash and
+/// SRAM filled in from the ELF's LOAD segments, plus the address execution
+/// should start at.
+pub struct MemoryImage {
+    pub flash: Vec<u8>,
+    pub sram: Vec<u8>,
+    pub entry: u32
+}
+
+fn copy_segment(dest: &mut [u8], dest_offset: u32, bytes: &[u8], seg: &LoadSegment, region: &'static str) -> Result<()> {
+    if seg.filesz > seg.memsz {
+        return Err(ElfParseError::InvalidFileHeader("p_filesz is larger than p_memsz"));
+    }
+
+    let start = dest_offset as usize;
+    let end = start + seg.memsz as usize;
+    if end > dest.len() {
+        return Err(ElfParseError::InvalidFileHeader(region));
+    }
+
+    let src_start = seg.offset as usize;
+    let src_end = src_start + seg.filesz as usize;
+    let src = match bytes.get(src_start..src_end) {
+        Some(src) => src,
+        None => return Err(ElfParseError::InvalidFileHeader("LOAD segment reaches past the end of the file"))
+    };
+
+    let filesz = seg.filesz as usize;
+    dest[start..start + filesz].copy_from_slice(src);
+    for b in &mut dest[start + filesz..end] {
+        *b = 0;
+    }
+    Ok(())
+}
+
+/// Loads `elf`'s LOAD segments into an AVR memory image: executable
+/// segments go into `flash` at their `p_paddr`, the rest go into `sram` at
+/// `p_vaddr - AVR_SRAM_BASE`, and the `.bss` tail of each is zero-filled up
+/// to `p_memsz`. `bytes` must be the same buffer `elf` was parsed from.
+pub fn load_image(elf: &ElfFile, bytes: &[u8], flash_size: usize, sram_size: usize) -> Result<MemoryImage> {
+    if elf.header.machine != ElfMachine::AVR {
+        return Err(ElfParseError::InvalidFileHeader("e_machine is not EM_AVR"));
+    }
+
+    let mut image = MemoryImage { flash: vec![0; flash_size], sram: vec![0; sram_size], entry: elf.header.entry };
+
+    for seg in load_segments(&elf.program_headers) {
+        if seg.x {
+            try!(copy_segment(&mut image.flash, seg.paddr, bytes, &seg, "LOAD segment does not fit in flash"));
+        } else {
+            let sram_offset = match seg.vaddr.checked_sub(AVR_SRAM_BASE) {
+                Some(sram_offset) => sram_offset,
+                None => return Err(ElfParseError::InvalidFileHeader("p_vaddr is below AVR_SRAM_BASE"))
+            };
+            try!(copy_segment(&mut image.sram, sram_offset, bytes, &seg, "LOAD segment does not fit in SRAM"));
+        }
+    }
+    Ok(image)
+}
+
@@ -4,12 +4,199 @@ use avrvm::elfparser;
 
 #[test]
 fn elf_header_read() {
-    let header = elfparser::read_elf_header("/home/saaadhu/code/personal/avrvm/tests/test.elf").unwrap();
+    let bytes: Vec<u8> = vec![
+        0x7F, 'E' as u8, 'L' as u8, 'F' as u8, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0,       // e_type: Executable
+        83, 0,      // e_machine: AVR
+        1, 0, 0, 0, // e_version
+        0, 0, 0, 0, // e_entry
+        0, 0, 0, 0, // e_phoff: no program headers
+        0, 0, 0, 0, // e_shoff: no sections
+        0, 0, 0, 0, // e_flags
+        52, 0,      // e_ehsize
+        0, 0,       // e_phentsize
+        0, 0,       // e_phnum
+        0, 0,       // e_shentsize
+        0, 0,       // e_shnum
+        0, 0,       // e_shstrndx
+    ];
+
+    let elf = elfparser::parse(&bytes).unwrap();
+    let header = elf.header;
     assert_eq!(header.class, elfparser::ElfClass::Bit32);
     assert_eq!(header.endianness, elfparser::ElfEndianness::Little);
     assert_eq!(header.ident_version, elfparser::ElfVersion::Current);
     assert_eq!(header.filetype, elfparser::ElfFileType::Executable);
-    assert_eq!(header.machine, elfparser::ElfMachine::Processor(83));
+    assert_eq!(header.machine, elfparser::ElfMachine::AVR);
     assert_eq!(header.version, elfparser::ElfVersion::Current);
     assert_eq!(header.entry, 0x0);
 }
+
+#[test]
+fn elf_parse_from_bytes() {
+    let bytes: Vec<u8> = vec![
+        0x7F, 'E' as u8, 'L' as u8, 'F' as u8, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0,       // e_type: Executable
+        83, 0,      // e_machine: AVR
+        1, 0, 0, 0, // e_version
+        0, 1, 0, 0, // e_entry: 0x100
+        52, 0, 0, 0, // e_phoff: right after the header
+        0, 0, 0, 0, // e_shoff
+        0, 0, 0, 0, // e_flags
+        52, 0,      // e_ehsize
+        32, 0,      // e_phentsize
+        0, 0,       // e_phnum: no program headers
+        0, 0,       // e_shentsize
+        0, 0,       // e_shnum
+        0, 0,       // e_shstrndx
+    ];
+
+    let elf = elfparser::parse(&bytes).unwrap();
+    assert_eq!(elf.header.machine, elfparser::ElfMachine::AVR);
+    assert_eq!(elf.header.entry, 0x100);
+    assert_eq!(elf.program_headers.len(), 0);
+}
+
+#[test]
+fn elf_parse_sections() {
+    let mut bytes: Vec<u8> = vec![
+        0x7F, 'E' as u8, 'L' as u8, 'F' as u8, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0,       // e_type: Executable
+        83, 0,      // e_machine: AVR
+        1, 0, 0, 0, // e_version
+        0, 0, 0, 0, // e_entry
+        0, 0, 0, 0, // e_phoff: no program headers
+        52, 0, 0, 0, // e_shoff: right after the header
+        0, 0, 0, 0, // e_flags
+        52, 0,      // e_ehsize
+        0, 0,       // e_phentsize
+        0, 0,       // e_phnum
+        40, 0,      // e_shentsize
+        1, 0,       // e_shnum: one section, the string table itself
+        0, 0,       // e_shstrndx: section 0 is the string table
+    ];
+    // Section header for the lone ".shstrtab" section, sitting right after the header.
+    bytes.extend_from_slice(&[
+        1, 0, 0, 0, // sh_name: offset 1 into the string table
+        3, 0, 0, 0, // sh_type: SHT_STRTAB
+        0, 0, 0, 0, // sh_flags
+        0, 0, 0, 0, // sh_addr
+        92, 0, 0, 0, // sh_offset: right after this section header
+        11, 0, 0, 0, // sh_size
+        0, 0, 0, 0, // sh_link
+        0, 0, 0, 0, // sh_info
+        1, 0, 0, 0, // sh_addralign
+        0, 0, 0, 0, // sh_entsize
+    ]);
+    // The string table itself: a leading NUL followed by ".shstrtab".
+    bytes.extend_from_slice(b"\0.shstrtab\0");
+
+    let elf = elfparser::parse(&bytes).unwrap();
+    assert_eq!(elf.sections.len(), 1);
+    assert_eq!(elf.sections[0].name, ".shstrtab");
+    assert!(elf.section_by_name(".shstrtab").is_some());
+    assert!(elf.section_by_name(".text").is_none());
+}
+
+#[test]
+fn elf_load_image() {
+    let mut bytes: Vec<u8> = vec![
+        0x7F, 'E' as u8, 'L' as u8, 'F' as u8, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0,       // e_type: Executable
+        83, 0,      // e_machine: AVR
+        1, 0, 0, 0, // e_version
+        0x10, 0, 0, 0, // e_entry: 0x10
+        52, 0, 0, 0, // e_phoff: right after the header
+        0, 0, 0, 0, // e_shoff
+        0, 0, 0, 0, // e_flags
+        52, 0,      // e_ehsize
+        32, 0,      // e_phentsize
+        1, 0,       // e_phnum: one LOAD segment
+        0, 0,       // e_shentsize
+        0, 0,       // e_shnum
+        0, 0,       // e_shstrndx
+    ];
+    // A single PT_LOAD, read+execute segment sitting right after the header.
+    bytes.extend_from_slice(&[
+        1, 0, 0, 0, // p_type: PT_LOAD
+        84, 0, 0, 0, // p_offset: right after this program header
+        0, 0, 0, 0, // p_vaddr
+        0, 0, 0, 0, // p_paddr
+        4, 0, 0, 0, // p_filesz
+        4, 0, 0, 0, // p_memsz
+        5, 0, 0, 0, // p_flags: PF_R | PF_X
+        1, 0, 0, 0, // p_align
+    ]);
+    bytes.extend_from_slice(&[0xAA, 0xBB, 0xCC, 0xDD]);
+
+    let elf = elfparser::parse(&bytes).unwrap();
+    let image = elfparser::load_image(&elf, &bytes, 256, 256).unwrap();
+    assert_eq!(&image.flash[0..4], &[0xAA, 0xBB, 0xCC, 0xDD]);
+    assert_eq!(image.entry, 0x10);
+}
+
+#[test]
+fn elf_parse_symbols() {
+    let mut bytes: Vec<u8> = vec![
+        0x7F, 'E' as u8, 'L' as u8, 'F' as u8, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        2, 0,       // e_type: Executable
+        83, 0,      // e_machine: AVR
+        1, 0, 0, 0, // e_version
+        0, 0, 0, 0, // e_entry
+        0, 0, 0, 0, // e_phoff: no program headers
+        52, 0, 0, 0, // e_shoff: right after the header
+        0, 0, 0, 0, // e_flags
+        52, 0,      // e_ehsize
+        0, 0,       // e_phentsize
+        0, 0,       // e_phnum
+        40, 0,      // e_shentsize
+        3, 0,       // e_shnum: NULL, .strtab, .symtab
+        1, 0,       // e_shstrndx: reuse .strtab for section names too
+    ];
+    // Section 0: the mandatory all-zero SHT_NULL section.
+    bytes.extend_from_slice(&[0; 40]);
+    // Section 1: .strtab, holding the symbol names.
+    bytes.extend_from_slice(&[
+        0, 0, 0, 0, // sh_name
+        3, 0, 0, 0, // sh_type: SHT_STRTAB
+        0, 0, 0, 0, // sh_flags
+        0, 0, 0, 0, // sh_addr
+        172, 0, 0, 0, // sh_offset: right after the section headers
+        6, 0, 0, 0, // sh_size
+        0, 0, 0, 0, // sh_link
+        0, 0, 0, 0, // sh_info
+        1, 0, 0, 0, // sh_addralign
+        0, 0, 0, 0, // sh_entsize
+    ]);
+    // Section 2: .symtab, linked to section 1 for names.
+    bytes.extend_from_slice(&[
+        0, 0, 0, 0, // sh_name
+        2, 0, 0, 0, // sh_type: SHT_SYMTAB
+        0, 0, 0, 0, // sh_flags
+        0, 0, 0, 0, // sh_addr
+        178, 0, 0, 0, // sh_offset: right after the string table
+        16, 0, 0, 0, // sh_size: one entry
+        1, 0, 0, 0, // sh_link: section 1 is the string table
+        0, 0, 0, 0, // sh_info
+        4, 0, 0, 0, // sh_addralign
+        16, 0, 0, 0, // sh_entsize
+    ]);
+    // The string table: a leading NUL followed by "main".
+    bytes.extend_from_slice(b"\0main\0");
+    // One symbol: "main" at 0x10, a function.
+    bytes.extend_from_slice(&[
+        1, 0, 0, 0, // st_name: offset 1 into the string table
+        0x10, 0, 0, 0, // st_value
+        0, 0, 0, 0, // st_size
+        0x12,       // st_info: STB_GLOBAL << 4 | STT_FUNC
+        0,          // st_other
+        0, 0,       // st_shndx
+    ]);
+
+    let elf = elfparser::parse(&bytes).unwrap();
+    assert_eq!(elf.symbols.len(), 1);
+    assert_eq!(elf.symbols[0].name, "main");
+    assert_eq!(elf.symbol_by_name("main"), Some(0x10));
+    assert_eq!(elf.symbol_by_name("missing"), None);
+    assert_eq!(elf.function_symbols().len(), 1);
+}